@@ -0,0 +1,185 @@
+use core::mem::MaybeUninit;
+
+/// A fixed-capacity ring buffer storing its `N` slots inline, with no heap
+/// allocation. The capacity is the const parameter `N`; the front/end/size
+/// arithmetic mirrors the allocating [`crate::ring_buffer::RingBuffer`].
+pub struct RingBuffer<T, const N: usize> {
+    data: [MaybeUninit<T>; N], // Only occupied slots hold live values
+    front: usize, // First to remove
+    end: usize, // Last to remove
+    is_empty: bool, // To distinguish 1 element and none
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    pub const fn new() -> Self {
+        Self {
+            // Safe: an array of `MaybeUninit` needs no initialization.
+            data: unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() },
+            front: 0, // Pop from front
+            end: 0, // Push to end
+            is_empty: true,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        if self.end > self.front {
+            self.end - self.front + 1
+        } else if self.end < self.front {
+            N - self.front + self.end + 1
+        } else if self.is_empty {
+            0
+        } else {
+            1
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.is_empty
+    }
+
+    pub fn add(&mut self, item: T) {
+        if self.is_empty {
+            self.is_empty = false;
+            // Reset to play safe
+            self.front = 0;
+            self.end = 0;
+        } else {
+            self.increment_end();
+            if self.front == self.end {
+                // Wrapped onto the oldest element; drop it before overwriting.
+                unsafe { self.data[self.end].assume_init_drop(); }
+                self.increment_front();
+            }
+        }
+        self.data[self.end] = MaybeUninit::new(item);
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        if self.is_empty {
+            None
+        } else {
+            Some(unsafe { self.data[self.front].assume_init_ref() })
+        }
+    }
+
+    pub fn remove(&mut self) -> Option<T> {
+        if self.is_empty {
+            None
+        } else {
+            let temp: T = unsafe { self.data[self.front].assume_init_read() };
+            if self.front == self.end { // Removing the only one
+                self.is_empty = true;
+            } else {
+                self.increment_front();
+            }
+            Some(temp)
+        }
+    }
+
+    /// Borrow the `i`-th element counting from `front`, or `None` when `i` is
+    /// out of bounds.
+    pub fn get(&self, i: usize) -> Option<&T> {
+        if i >= self.size() {
+            None
+        } else {
+            let idx = (self.front + i) % N;
+            Some(unsafe { self.data[idx].assume_init_ref() })
+        }
+    }
+
+    /// Mutably borrow the `i`-th element counting from `front`, or `None` when
+    /// `i` is out of bounds.
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        if i >= self.size() {
+            None
+        } else {
+            let idx = (self.front + i) % N;
+            Some(unsafe { self.data[idx].assume_init_mut() })
+        }
+    }
+
+    fn increment_front(&mut self) {
+        self.front += 1;
+        self.front %= N;
+    }
+
+    fn increment_end(&mut self) {
+        self.end += 1;
+        self.end %= N;
+    }
+}
+
+impl<T, const N: usize> core::ops::Index<usize> for RingBuffer<T, N> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        self.get(i).expect("index out of bounds")
+    }
+}
+
+impl<T, const N: usize> core::ops::IndexMut<usize> for RingBuffer<T, N> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        self.get_mut(i).expect("index out of bounds")
+    }
+}
+
+impl<T, const N: usize> Default for RingBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for RingBuffer<T, N> {
+    fn drop(&mut self) {
+        if self.is_empty {
+            return;
+        }
+        // Drop only the initialized elements, walking front..end logically.
+        let mut i = self.front;
+        loop {
+            unsafe { self.data[i].assume_init_drop(); }
+            if i == self.end {
+                break;
+            }
+            i = (i + 1) % N;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn create_fixed_ring_buffer() {
+        let buf = RingBuffer::<i32, 7>::new();
+        assert!(buf.is_empty());
+        assert!(buf.size() == 0);
+    }
+
+    #[test]
+    fn circularity() {
+        let mut buf = RingBuffer::<i32, 7>::new();
+
+        for i in 1..=8 {
+            buf.add(i);
+            if i < 8 {
+                assert!(buf.size() == i as usize);
+            }
+        }
+        assert!(buf.size() == 7);
+
+        if let Some(val) = buf.peek() {
+            assert!(*val == 2);
+        }
+
+        assert!(buf.get(0) == Some(&2));
+        assert!(buf.get(6) == Some(&8));
+        assert!(buf.get(7).is_none());
+
+        for i in 2..=8 {
+            assert!(buf.remove() == Some(i));
+        }
+        assert!(buf.is_empty());
+    }
+}
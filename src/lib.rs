@@ -0,0 +1,16 @@
+//! Ring buffer implementations.
+//!
+//! Two flavours are provided. The allocating [`ring_buffer::RingBuffer`] keeps
+//! its fixed-capacity storage on the heap and is gated behind the `alloc` feature (enabled by
+//! default). The [`const_generic::RingBuffer`] stores its elements inline in a
+//! `[MaybeUninit<T>; N]` and needs no allocator, so it works in `#![no_std]`
+//! and embedded contexts.
+#![cfg_attr(not(feature = "alloc"), no_std)]
+
+#[cfg(feature = "alloc")]
+pub mod ring_buffer;
+
+pub mod const_generic;
+
+#[cfg(feature = "alloc")]
+pub use ring_buffer::RingBuffer;
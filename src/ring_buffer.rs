@@ -1,17 +1,20 @@
+use std::cell::UnsafeCell;
+use std::mem::MaybeUninit;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 pub struct RingBuffer<T> {
-    data: Vec<T>,
+    data: Vec<MaybeUninit<T>>, // Only occupied slots hold live values
     front: usize, // First to remove
     end: usize, // Last to remove
     capacity: usize, // Fixed
     is_empty: bool, // To distinguish 1 element and none
 }
 
-impl<T> RingBuffer<T>
-where T: Copy + Default
-{
+impl<T> RingBuffer<T> {
     pub fn new(capacity: usize) -> Self {
         Self {
-            data: vec![T::default(); capacity],
+            data: (0..capacity).map(|_| MaybeUninit::uninit()).collect(),
             front: 0, // Pop from front
             end: 0, // Push to end
             capacity,
@@ -19,15 +22,6 @@ where T: Copy + Default
         }
     }
 
-    /// Create a new RingBuffer from an existing slice, in ascending order of indices in the slice
-    pub fn new_from_slice(source: &[T]) -> Self {
-        let mut buf = Self::new(source.len());
-        for i in source.iter() {
-            buf.add(*i);
-        }
-        buf
-    }
-
     pub fn size(&self) -> usize {
         if self.end > self.front {
             self.end - self.front + 1
@@ -53,24 +47,38 @@ where T: Copy + Default
         } else {
             self.increment_end();
             if self.front == self.end {
+                // Wrapped onto the oldest element; drop it before overwriting.
+                unsafe { self.data[self.end].assume_init_drop(); }
                 self.increment_front();
             }
         }
-        self.data[self.end] = item;
+        self.data[self.end] = MaybeUninit::new(item);
     }
 
-    /// Add items to the RingBuffer, in ascending order of indices in the slice
-    pub fn add_from_slice(&mut self, items: &[T]) {
-        for item in items.iter() {
-            self.add(*item);
+    /// Prepend an item at `front`, evicting the tail element when full. Mirrors
+    /// [`add`](Self::add)'s overwrite-on-full semantics, but at the other end.
+    pub fn push_front(&mut self, item: T) {
+        if self.is_empty {
+            self.is_empty = false;
+            // Reset to play safe
+            self.front = 0;
+            self.end = 0;
+        } else {
+            self.decrement_front();
+            if self.front == self.end {
+                // Wrapped onto the newest element; drop it before overwriting.
+                unsafe { self.data[self.front].assume_init_drop(); }
+                self.decrement_end();
+            }
         }
+        self.data[self.front] = MaybeUninit::new(item);
     }
 
-    pub fn peek(&self) -> Option<T> {
+    pub fn peek(&self) -> Option<&T> {
         if self.is_empty {
             None
         } else {
-            Some(self.data[self.front])
+            Some(unsafe { self.data[self.front].assume_init_ref() })
         }
     }
 
@@ -78,7 +86,7 @@ where T: Copy + Default
         if self.is_empty {
             None
         } else {
-            let temp: T = self.data[self.front];
+            let temp: T = unsafe { self.data[self.front].assume_init_read() };
             if self.front == self.end { // Removing the only one
                 self.is_empty = true;
             } else {
@@ -88,6 +96,99 @@ where T: Copy + Default
         }
     }
 
+    /// Remove and return the element at `end`, or `None` when empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty {
+            None
+        } else {
+            let temp: T = unsafe { self.data[self.end].assume_init_read() };
+            if self.front == self.end { // Removing the only one
+                self.is_empty = true;
+            } else {
+                self.decrement_end();
+            }
+            Some(temp)
+        }
+    }
+
+    /// Split the buffer into a [`Producer`] and a [`Consumer`] that share a
+    /// single lock-free ring, so one thread can `push` while another `pop`s
+    /// without locking. One physical slot is sacrificed to tell a full ring
+    /// (`tail + 1 == head`) apart from an empty one (`tail == head`), so the
+    /// shared storage holds `capacity + 1` slots and keeps the advertised
+    /// usable capacity. The current contents are moved across in queue order.
+    pub fn split(self) -> (Producer<T>, Consumer<T>) {
+        let slots = self.capacity + 1;
+        let buf: Vec<UnsafeCell<MaybeUninit<T>>> = (0..slots)
+            .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+            .collect();
+        let shared = Arc::new(Shared {
+            buf,
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+            capacity: slots,
+        });
+
+        // Seed the ring with the existing elements, front to end.
+        let mut buf = self;
+        let mut tail = 0;
+        while let Some(item) = buf.remove() {
+            unsafe { (*shared.buf[tail].get()).write(item); }
+            tail += 1;
+        }
+        shared.tail.store(tail, Ordering::Release);
+
+        (
+            Producer { shared: Arc::clone(&shared) },
+            Consumer { shared },
+        )
+    }
+
+    /// Borrow the `i`-th element counting from `front`, or `None` when `i` is
+    /// out of bounds. Translates the logical index to the physical slot
+    /// `(front + i) % capacity`.
+    pub fn get(&self, i: usize) -> Option<&T> {
+        if i >= self.size() {
+            None
+        } else {
+            let idx = (self.front + i) % self.capacity;
+            Some(unsafe { self.data[idx].assume_init_ref() })
+        }
+    }
+
+    /// Mutably borrow the `i`-th element counting from `front`, or `None` when
+    /// `i` is out of bounds.
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        if i >= self.size() {
+            None
+        } else {
+            let idx = (self.front + i) % self.capacity;
+            Some(unsafe { self.data[idx].assume_init_mut() })
+        }
+    }
+
+    /// Iterate over the elements in queue order (front to end).
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            data: &self.data,
+            head: self.front,
+            len: self.size(),
+        }
+    }
+
+    /// Mutably iterate over the elements in queue order (front to end).
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let head = self.front;
+        let len = self.size();
+        IterMut {
+            ptr: self.data.as_mut_ptr(),
+            capacity: self.capacity,
+            head,
+            len,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
     fn increment_front(&mut self) {
         self.front += 1;
         self.front %= self.capacity;
@@ -97,6 +198,383 @@ where T: Copy + Default
         self.end += 1;
         self.end %= self.capacity;
     }
+
+    fn decrement_front(&mut self) {
+        self.front = (self.front + self.capacity - 1) % self.capacity;
+    }
+
+    fn decrement_end(&mut self) {
+        self.end = (self.end + self.capacity - 1) % self.capacity;
+    }
+}
+
+impl<T> RingBuffer<T>
+where T: Clone
+{
+    /// Create a new RingBuffer from an existing slice, in ascending order of indices in the slice
+    pub fn new_from_slice(source: &[T]) -> Self {
+        let mut buf = Self::new(source.len());
+        for i in source.iter() {
+            buf.add(i.clone());
+        }
+        buf
+    }
+
+    /// Add items to the RingBuffer, in ascending order of indices in the slice
+    pub fn add_from_slice(&mut self, items: &[T]) {
+        for item in items.iter() {
+            self.add(item.clone());
+        }
+    }
+}
+
+impl<T> Drop for RingBuffer<T> {
+    fn drop(&mut self) {
+        if self.is_empty {
+            return;
+        }
+        // Drop only the initialized elements, walking front..end logically.
+        let mut i = self.front;
+        loop {
+            unsafe { self.data[i].assume_init_drop(); }
+            if i == self.end {
+                break;
+            }
+            i = (i + 1) % self.capacity;
+        }
+    }
+}
+
+impl<T> std::ops::Index<usize> for RingBuffer<T> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        self.get(i).expect("index out of bounds")
+    }
+}
+
+impl<T> std::ops::IndexMut<usize> for RingBuffer<T> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        self.get_mut(i).expect("index out of bounds")
+    }
+}
+
+/// Shared-reference iterator yielding elements in queue order. Walks logical
+/// indices modulo `capacity`, so a wrapped buffer (`end < front`) is handled
+/// the same as a contiguous one.
+pub struct Iter<'a, T> {
+    data: &'a [MaybeUninit<T>],
+    head: usize,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        let item = unsafe { self.data[self.head].assume_init_ref() };
+        self.head = (self.head + 1) % self.data.len();
+        self.len -= 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let idx = (self.head + self.len) % self.data.len();
+        Some(unsafe { self.data[idx].assume_init_ref() })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// Mutable-reference iterator yielding elements in queue order.
+pub struct IterMut<'a, T> {
+    ptr: *mut MaybeUninit<T>,
+    capacity: usize,
+    head: usize,
+    len: usize,
+    _marker: std::marker::PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+        let slot = unsafe { &mut *self.ptr.add(self.head) };
+        self.head = (self.head + 1) % self.capacity;
+        self.len -= 1;
+        Some(unsafe { slot.assume_init_mut() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let idx = (self.head + self.len) % self.capacity;
+        let slot = unsafe { &mut *self.ptr.add(idx) };
+        Some(unsafe { slot.assume_init_mut() })
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// Owning iterator produced by [`IntoIterator`], yielding elements front to end.
+pub struct IntoIter<T> {
+    data: Vec<MaybeUninit<T>>,
+    head: usize,
+    len: usize,
+    capacity: usize,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let item = unsafe { self.data[self.head].assume_init_read() };
+        self.head = (self.head + 1) % self.capacity;
+        self.len -= 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let idx = (self.head + self.len) % self.capacity;
+        Some(unsafe { self.data[idx].assume_init_read() })
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        // Drop whatever elements were not yielded.
+        while self.next().is_some() {}
+    }
+}
+
+impl<T> IntoIterator for RingBuffer<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        let len = self.size();
+        // Defuse `RingBuffer`'s own `Drop` and take ownership of the storage.
+        let me = std::mem::ManuallyDrop::new(self);
+        let data = unsafe { std::ptr::read(&me.data) };
+        IntoIter {
+            data,
+            head: me.front,
+            len,
+            capacity: me.capacity,
+        }
+    }
+}
+
+impl<T> FromIterator<T> for RingBuffer<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let items: Vec<T> = iter.into_iter().collect();
+        let mut buf = Self::new(items.len());
+        for item in items {
+            buf.add(item);
+        }
+        buf
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for RingBuffer<T>
+where T: serde::Serialize
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: serde::Serializer
+    {
+        use serde::ser::SerializeSeq;
+        // Emit elements in logical queue order, not raw slot order.
+        let mut seq = serializer.serialize_seq(Some(self.size()))?;
+        for item in self.iter() {
+            seq.serialize_element(item)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for RingBuffer<T>
+where T: serde::Deserialize<'de>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: serde::Deserializer<'de>
+    {
+        struct RingBufferVisitor<T>(std::marker::PhantomData<T>);
+
+        impl<'de, T> serde::de::Visitor<'de> for RingBufferVisitor<T>
+        where T: serde::Deserialize<'de>
+        {
+            type Value = RingBuffer<T>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a sequence of elements")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where A: serde::de::SeqAccess<'de>
+            {
+                let mut items: Vec<T> = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(item) = seq.next_element()? {
+                    items.push(item);
+                }
+                // Capacity equals the number of elements, like `new_from_slice`.
+                let mut buf = RingBuffer::new(items.len());
+                for item in items {
+                    buf.add(item);
+                }
+                Ok(buf)
+            }
+        }
+
+        deserializer.deserialize_seq(RingBufferVisitor(std::marker::PhantomData))
+    }
+}
+
+/// Storage shared between a [`Producer`] and a [`Consumer`]. `head` is the
+/// slot the consumer reads from, `tail` the slot the producer writes to.
+struct Shared<T> {
+    buf: Vec<UnsafeCell<MaybeUninit<T>>>,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+    capacity: usize,
+}
+
+// The producer only ever touches `tail`/its slot and the consumer only ever
+// touches `head`/its slot, so sharing the cells across threads is sound.
+unsafe impl<T: Send> Send for Shared<T> {}
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+impl<T> Shared<T> {
+    fn len(&self) -> usize {
+        let head = self.head.load(Ordering::Acquire);
+        let tail = self.tail.load(Ordering::Acquire);
+        (tail + self.capacity - head) % self.capacity
+    }
+
+    fn is_empty(&self) -> bool {
+        self.head.load(Ordering::Acquire) == self.tail.load(Ordering::Acquire)
+    }
+
+    fn is_full(&self) -> bool {
+        let tail = self.tail.load(Ordering::Acquire);
+        let head = self.head.load(Ordering::Acquire);
+        (tail + 1) % self.capacity == head
+    }
+}
+
+impl<T> Drop for Shared<T> {
+    fn drop(&mut self) {
+        // Drop the elements still in flight between head and tail.
+        let mut i = *self.head.get_mut();
+        let tail = *self.tail.get_mut();
+        while i != tail {
+            unsafe { (*self.buf[i].get()).assume_init_drop(); }
+            i = (i + 1) % self.capacity;
+        }
+    }
+}
+
+/// The writing half of a [`RingBuffer::split`] pair.
+pub struct Producer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Producer<T> {
+    /// Push an item, returning it back as `Err` when the ring is full.
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        let tail = self.shared.tail.load(Ordering::Acquire);
+        let head = self.shared.head.load(Ordering::Acquire);
+        let next = (tail + 1) % self.shared.capacity;
+        if next == head {
+            return Err(item); // Full
+        }
+        unsafe { (*self.shared.buf[tail].get()).write(item); }
+        self.shared.tail.store(next, Ordering::Release);
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.shared.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shared.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.shared.is_full()
+    }
+}
+
+/// The reading half of a [`RingBuffer::split`] pair.
+pub struct Consumer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Consumer<T> {
+    /// Pop the front item, or `None` when the ring is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let head = self.shared.head.load(Ordering::Acquire);
+        let tail = self.shared.tail.load(Ordering::Acquire);
+        if head == tail {
+            return None; // Empty
+        }
+        let item = unsafe { (*self.shared.buf[head].get()).assume_init_read() };
+        self.shared.head.store((head + 1) % self.shared.capacity, Ordering::Release);
+        Some(item)
+    }
+
+    pub fn len(&self) -> usize {
+        self.shared.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shared.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.shared.is_full()
+    }
 }
 
 #[cfg(test)]
@@ -116,18 +594,18 @@ mod test {
         buf.add(3);
         if let Some(val) = buf.peek() {
             assert!(buf.size() == 1);
-            assert!(val == 3);
+            assert!(*val == 3);
         } else {
             panic!();
         }
 
         buf.add(4);
         if let Some(val) = buf.peek() {
-            assert!(val == 3); // still 3 because it is a queue
+            assert!(*val == 3); // still 3 because it is a queue
         } else {
             panic!();
         }
-        
+
         assert!(buf.size() == 2);
 
         if let Some(val) = buf.remove() {
@@ -140,7 +618,7 @@ mod test {
 
         if let Some(val) = buf.peek() {
             assert!(!buf.is_empty());
-            assert!(val == 4); // 4 because 3 is dequeued
+            assert!(*val == 4); // 4 because 3 is dequeued
         } else {
             panic!();
         }
@@ -161,7 +639,7 @@ mod test {
             assert!(buf.size() == i as usize);
         }
         if let Some(val) = buf.peek() {
-            assert!(val == 1);
+            assert!(*val == 1);
         }
 
         assert!(buf.size() == 7);
@@ -189,14 +667,14 @@ mod test {
         assert!(buf.size() == 7);
 
         if let Some(val) = buf.peek() {
-            assert!(val == 2);
+            assert!(*val == 2);
         }
 
         buf.add(9);
         assert!(buf.size() == 7);
 
         if let Some(val) = buf.peek() {
-            assert!(val == 3);
+            assert!(*val == 3);
         }
 
         for i in 3..=9 {
@@ -214,18 +692,131 @@ mod test {
         }
     }
 
+    #[test]
+    fn split_into_producer_consumer() {
+        let buf = RingBuffer::<i32>::new_from_slice(&[1, 2, 3]);
+        let (mut prod, mut cons) = buf.split();
+
+        assert!(cons.len() == 3);
+        assert!(!cons.is_empty());
+
+        // The two oldest come out first, preserving queue order.
+        assert!(cons.pop() == Some(1));
+        assert!(prod.push(4).is_ok());
+        assert!(cons.pop() == Some(2));
+
+        // Capacity is 3, so one more push fills the ring.
+        assert!(prod.push(5).is_ok());
+        assert!(prod.is_full());
+        assert!(prod.push(6) == Err(6));
+
+        assert!(cons.pop() == Some(3));
+        assert!(cons.pop() == Some(4));
+        assert!(cons.pop() == Some(5));
+        assert!(cons.pop().is_none());
+        assert!(cons.is_empty());
+    }
+
+    #[test]
+    fn stores_non_copy_types() {
+        let mut buf = RingBuffer::<String>::new(2);
+        buf.add(String::from("a"));
+        buf.add(String::from("b"));
+        buf.add(String::from("c")); // Evicts "a", which must be dropped.
+
+        assert!(buf.size() == 2);
+        assert!(buf.peek() == Some(&String::from("b")));
+        assert!(buf.remove() == Some(String::from("b")));
+        assert!(buf.remove() == Some(String::from("c")));
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn iterate_in_queue_order() {
+        // Force a wraparound: capacity 4, push 6 so end < front.
+        let mut buf = RingBuffer::<i32>::new(4);
+        buf.add_from_slice(&[1, 2, 3, 4, 5, 6]);
+        // Oldest two evicted, contents are 3, 4, 5, 6.
+
+        let forward: Vec<i32> = buf.iter().copied().collect();
+        assert!(forward == vec![3, 4, 5, 6]);
+
+        let backward: Vec<i32> = buf.iter().rev().copied().collect();
+        assert!(backward == vec![6, 5, 4, 3]);
+
+        for val in buf.iter_mut() {
+            *val += 10;
+        }
+        let bumped: Vec<i32> = buf.iter().copied().collect();
+        assert!(bumped == vec![13, 14, 15, 16]);
+
+        let owned: Vec<i32> = buf.into_iter().collect();
+        assert!(owned == vec![13, 14, 15, 16]);
+    }
+
+    #[test]
+    fn random_access() {
+        let mut buf = RingBuffer::<i32>::new(4);
+        buf.add_from_slice(&[1, 2, 3, 4, 5, 6]); // Wraps; contents 3, 4, 5, 6.
+
+        assert!(buf.get(0) == Some(&3));
+        assert!(buf.get(3) == Some(&6));
+        assert!(buf.get(4).is_none());
+
+        assert!(buf[1] == 4);
+        buf[1] = 40;
+        assert!(buf[1] == 40);
+
+        if let Some(val) = buf.get_mut(0) {
+            *val = 30;
+        }
+        assert!(buf[0] == 30);
+    }
+
+    #[test]
+    fn collect_via_from_iterator() {
+        let buf: RingBuffer<i32> = (1..=3).collect();
+        assert!(buf.size() == 3);
+        let collected: Vec<i32> = buf.iter().copied().collect();
+        assert!(collected == vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn deque_operations() {
+        let mut buf = RingBuffer::<i32>::new(3);
+
+        buf.push_front(2);
+        buf.push_front(1); // Contents: 1, 2
+        buf.add(3); // Contents: 1, 2, 3
+        assert!(buf.size() == 3);
+        assert!(buf.peek() == Some(&1));
+
+        // Full: pushing at the front evicts the tail (3).
+        buf.push_front(0); // Contents: 0, 1, 2
+        assert!(buf.size() == 3);
+        assert!(buf.get(0) == Some(&0));
+        assert!(buf.get(2) == Some(&2));
+
+        assert!(buf.pop_back() == Some(2));
+        assert!(buf.pop_back() == Some(1));
+        assert!(buf.size() == 1);
+        assert!(buf.pop_back() == Some(0));
+        assert!(buf.is_empty());
+        assert!(buf.pop_back().is_none());
+    }
+
     #[test]
     fn new_buf_from_slice() {
         let mut buf = RingBuffer::<i32>::new_from_slice(&[9,4,1,5,6]);
         if let Some(val) = buf.peek() {
-            assert!(val == 9);
+            assert!(*val == 9);
         }
         assert!(buf.size() == 5);
 
         buf.add(10);
         assert!(buf.size() == 5);
         if let Some(val) = buf.peek() {
-            assert!(val == 4);
+            assert!(*val == 4);
         }
 
         if let Some(val) = buf.remove() {
@@ -240,7 +831,7 @@ mod test {
         buf.add_from_slice(&[1,2,3,4]);
         assert!(buf.size() == 5);
         if let Some(val) = buf.peek() {
-            assert!(val == 6);
+            assert!(*val == 6);
         }
     }
-}
\ No newline at end of file
+}